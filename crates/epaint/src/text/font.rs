@@ -75,6 +75,35 @@ pub struct GlyphAllocation {
 
     /// UV rectangle for drawing.
     pub uv_rect: UvRect,
+
+    /// Present if `uv_rect` holds a signed-distance field rather than a coverage mask.
+    ///
+    /// The renderer should sample the texture and threshold around `0.5` (with some
+    /// antialiasing band either side) instead of using it as a coverage alpha directly.
+    pub sdf: Option<GlyphSdfInfo>,
+
+    /// `true` if `uv_rect` holds a full-color glyph (e.g. a `BitmapPremulBgra32` color emoji
+    /// strike) rather than a grayscale coverage mask.
+    ///
+    /// The renderer should sample the texture as-is instead of tinting it by the vertex/text
+    /// color, the way it does for ordinary (non-colored) glyphs.
+    pub colored: bool,
+}
+
+/// Metrics needed to reconstruct screen-space distances from a rasterized SDF glyph.
+///
+/// See [`GlyphAllocation::sdf`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GlyphSdfInfo {
+    /// How far, in pixels at `ref_em_px`, the stored distance field spreads to either side of
+    /// the glyph edge before clamping to `0` or `255`.
+    pub spread_px: f32,
+
+    /// The em-size (in physical pixels) the glyph was rasterized at to produce the SDF.
+    ///
+    /// Since one SDF entry is shared across all sizes, the renderer needs this to scale
+    /// `spread_px` to whatever size the glyph is actually being drawn at.
+    pub ref_em_px: f32,
 }
 
 // ----------------------------------------------------------------------------
@@ -86,7 +115,114 @@ pub struct FontImpl {
     ab_glyph_font: ab_glyph::FontArc,
     tweak: FontTweak,
     glyph_info_cache: ahash::HashMap<char, GlyphInfo>,
-    glyph_alloc_cache: ahash::HashMap<(GlyphInfo, OrderedFloat<f32>), GlyphAllocation>,
+    /// Keyed by `(glyph, scale, sub_pixel_bin)` — see [`FontTweak::subpixel_positions`].
+    glyph_alloc_cache: ahash::HashMap<(GlyphInfo, OrderedFloat<f32>, u8), GlyphAllocation>,
+
+    /// Cap-height (height of e.g. 'H'), in physical pixels, measured from rasterized glyph
+    /// data at a given physical scale. Used to normalize apparent letter sizes across faces.
+    ///
+    /// `None` means the cap-height couldn't be measured at that scale (no reference glyph
+    /// had an outline).
+    cap_height_px_cache: ahash::HashMap<OrderedFloat<f32>, Option<f32>>,
+
+    /// Size-independent raster cache for [`FontTweak::sdf`] mode: one entry per glyph id,
+    /// holding only the reference-em-pixel atlas geometry, since the rasterized distance
+    /// field itself is reused at every screen size. `None` means the glyph has no outline.
+    /// The size-dependent [`GlyphAllocation`] (advance width, draw-space `uv_rect`) is
+    /// recomputed from this on every call — see [`Self::allocate_sdf_glyph`].
+    sdf_glyph_raster_cache: ahash::HashMap<GlyphInfo, Option<SdfGlyphRaster>>,
+
+    /// LRU-order tracker for `glyph_alloc_cache` entries, for eviction under
+    /// [`FontTweak::glyph_cache_capacity`]. Never hands out an entry touched during the
+    /// frame currently being built, since its atlas rectangle may already be referenced by
+    /// this frame's mesh.
+    glyph_alloc_lru: LruTracker<(GlyphInfo, OrderedFloat<f32>, u8)>,
+
+    /// Same as `glyph_alloc_lru`, but for `sdf_glyph_raster_cache`.
+    sdf_glyph_alloc_lru: LruTracker<GlyphInfo>,
+}
+
+/// Access-order tracker used to evict the least-recently-used entry from a glyph cache once
+/// it's over capacity, without a linear scan over every entry on every touch.
+///
+/// Entries touched during the *current* frame are never handed out by `pop_lru`: their atlas
+/// rectangle may already be referenced by this frame's mesh, so reusing it would make those
+/// already-queued quads sample a different glyph later in the same frame.
+#[derive(Default)]
+struct LruTracker<K> {
+    /// Next access-order counter to hand out; higher means more recently used.
+    next_counter: u64,
+    /// Access order (ascending, so the first entry is least-recently-used) -> key.
+    by_recency: std::collections::BTreeMap<u64, K>,
+    /// key -> (access-order counter, frame it was last touched on).
+    by_key: ahash::HashMap<K, (u64, u64)>,
+}
+
+impl<K: Copy + Eq + std::hash::Hash> LruTracker<K> {
+    fn len(&self) -> usize {
+        self.by_key.len()
+    }
+
+    /// Marks `key` as used on `frame`, making it the most-recently-used entry.
+    fn touch(&mut self, key: K, frame: u64) {
+        if let Some((old_counter, _)) = self.by_key.get(&key) {
+            self.by_recency.remove(old_counter);
+        }
+        let counter = self.next_counter;
+        self.next_counter += 1;
+        self.by_recency.insert(counter, key);
+        self.by_key.insert(key, (counter, frame));
+    }
+
+    /// Removes and returns the least-recently-used key not touched on `current_frame`, if any.
+    ///
+    /// Every `touch` during the current frame assigns a higher counter than anything touched
+    /// in an earlier frame, so current-frame entries always form a contiguous suffix of
+    /// `by_recency`. That makes the common case (something is actually stale) an O(1) walk
+    /// from the least-recently-used end; only the degenerate case where *every* remaining
+    /// entry was already touched this frame costs O(n), since we then have to scan past all of
+    /// them before concluding nothing can be evicted yet.
+    fn pop_lru(&mut self, current_frame: u64) -> Option<K> {
+        let stale_counter = self.by_recency.iter().find_map(|(&counter, key)| {
+            let (_, last_used_frame) = self.by_key[key];
+            (last_used_frame != current_frame).then_some(counter)
+        })?;
+        let key = self.by_recency.remove(&stale_counter)?;
+        self.by_key.remove(&key);
+        Some(key)
+    }
+}
+
+/// How far, in pixels at [`FontImpl::SDF_REF_EM_PX`], an SDF glyph's distance field spreads to
+/// either side of the glyph edge. See [`FontTweak::sdf`].
+const SDF_SPREAD_PX: f32 = 4.0;
+
+/// Em-size (in physical pixels) that SDF glyphs are rasterized at.
+///
+/// Large enough that the distance field stays crisp when later sampled at much bigger sizes
+/// (zoomed or animated text), while still cheap to rasterize once per glyph.
+const SDF_REF_EM_PX: f32 = 64.0;
+
+/// Maps a signed distance (in pixels, positive = inside the glyph) to the `0..=255` gray level
+/// stored in the atlas: clamps to `+-spread_px`, then remaps linearly to `0..=1` around `0.5`
+/// at the glyph edge, the convention [`GlyphAllocation::sdf`] documents for renderers.
+#[inline]
+fn sdf_signed_distance_to_gray(signed_px: f32, spread_px: f32) -> u8 {
+    let remapped = (signed_px / spread_px).clamp(-1.0, 1.0) * 0.5 + 0.5;
+    (remapped * 255.0).round() as u8
+}
+
+/// Cached atlas geometry for one glyph's SDF raster, in [`SDF_REF_EM_PX`] reference-pixel
+/// units. Size-independent, so it's valid regardless of the `font_size`/`pixels_per_point`
+/// the glyph is actually drawn at — see [`FontImpl::sdf_glyph_raster_cache`].
+#[derive(Clone, Copy, Debug)]
+struct SdfGlyphRaster {
+    /// Top-left corner of the padded distance-field rectangle in the atlas.
+    min: [u16; 2],
+    /// Bottom-right corner (exclusive) of the padded distance-field rectangle in the atlas.
+    max: [u16; 2],
+    /// Offset from the glyph origin to the raster's top-left corner, in reference-em pixels.
+    offset_at_ref_em_px: Vec2,
 }
 
 trait FontExt {
@@ -123,9 +259,40 @@ impl FontImpl {
             tweak,
             glyph_info_cache: Default::default(),
             glyph_alloc_cache: Default::default(),
+            cap_height_px_cache: Default::default(),
+            sdf_glyph_raster_cache: Default::default(),
+            glyph_alloc_lru: Default::default(),
+            sdf_glyph_alloc_lru: Default::default(),
         }
     }
 
+    /// Measures the cap-height (the height of an uppercase glyph like 'H') of this face, in
+    /// physical pixels, at the given rasterization `scale`.
+    ///
+    /// This is measured from actual rasterized glyph data (`outline_glyph().px_bounds()`)
+    /// rather than trusted metrics tables, since those are often inconsistent across fonts.
+    /// Tries `'H'`, then falls back to `'I'`, then `'X'`. Returns `None` if none of those
+    /// have an outline in this face. The result is cached per `scale`.
+    fn cap_height_px(&mut self, scale: f32) -> Option<f32> {
+        if let Some(&cached) = self.cap_height_px_cache.get(&scale.into()) {
+            return cached;
+        }
+
+        let cap_height_px = ['H', 'I', 'X'].iter().find_map(|&c| {
+            let glyph_id = self.ab_glyph_font.glyph_id(c);
+            if glyph_id.0 == 0 {
+                return None;
+            }
+            let glyph = glyph_id.with_scale_and_position(scale, ab_glyph::Point { x: 0.0, y: 0.0 });
+            self.ab_glyph_font
+                .outline_glyph(glyph)
+                .map(|glyph| glyph.px_bounds().height())
+        });
+
+        self.cap_height_px_cache.insert(scale.into(), cap_height_px);
+        cap_height_px
+    }
+
     /// Code points that will always be replaced by the replacement character.
     ///
     /// See also [`invisible_char`].
@@ -225,11 +392,16 @@ impl FontImpl {
         glyph_id: ab_glyph::GlyphId,
         font_size: f32,
         pixels_per_point: f32,
+        cap_height_scale: f32,
     ) -> f32 {
         // Round to an even number of physical pixels to get even kerning.
         // See https://github.com/emilk/egui/issues/382
+        //
+        // `cap_height_scale` matches the rasterization scale `allocate_glyph` uses for this
+        // face (see `FontTweak::match_cap_height`), so kerning stays consistent with the
+        // advance widths the caller is actually drawing at.
         self.ab_glyph_font
-            .pt_scaled((font_size * self.tweak.scale * pixels_per_point).round())
+            .pt_scaled((font_size * self.tweak.scale * cap_height_scale * pixels_per_point).round())
             .kern(last_glyph_id, glyph_id)
             / pixels_per_point
     }
@@ -258,45 +430,60 @@ impl FontImpl {
         atlas: &mut TextureAtlas,
         font_size: f32,
         pixels_per_point: f32,
+        cap_height_scale: f32,
+        pen_x_in_points: f32,
     ) -> GlyphAllocation {
         if !glyph_info.visible {
             return GlyphAllocation::default();
         }
+
+        if self.tweak.sdf {
+            return self.allocate_sdf_glyph(glyph_info, atlas, font_size, pixels_per_point, cap_height_scale);
+        }
+
         // Round to an even number of physical pixels to get even kerning.
         // See https://github.com/emilk/egui/issues/382
+        //
+        // `cap_height_scale` additionally corrects for `FontTweak::match_cap_height`: it is
+        // 1.0 unless this is a non-primary face whose cap-height has been scaled to match the
+        // primary face's, in which case it nudges the rasterization (and thus advance-width)
+        // scale so the two faces read as the same apparent size.
         let scale = self
             .ab_glyph_font
-            .pt_scale_factor(font_size * self.tweak.scale * pixels_per_point)
+            .pt_scale_factor(font_size * self.tweak.scale * cap_height_scale * pixels_per_point)
             .round();
-        let entry = match self.glyph_alloc_cache.entry((glyph_info, scale.into())) {
-            std::collections::hash_map::Entry::Occupied(glyph_alloc) => {
-                return *glyph_alloc.get();
-            }
-            std::collections::hash_map::Entry::Vacant(entry) => entry,
+
+        // Quantize the fractional physical pen x-position into `num_bins` sub-pixel positions,
+        // the classic dynamic-glyph-cache trick for sharper text at non-integer pen positions.
+        // 1 bin is the old, purely-integer-pixel behavior.
+        let num_bins = self.tweak.subpixel_positions.max(1);
+        let sub_pixel_bin = if num_bins <= 1 {
+            0
+        } else {
+            let pen_x_in_pixels = pen_x_in_points * pixels_per_point;
+            ((pen_x_in_pixels.fract() * num_bins as f32).floor() as i32).rem_euclid(num_bins as i32)
+                as u8
         };
+        let sub_pixel_offset_in_pixels = sub_pixel_bin as f32 / num_bins as f32;
+
+        let key = (glyph_info, scale.into(), sub_pixel_bin);
+        if let Some(&cached) = self.glyph_alloc_cache.get(&key) {
+            self.touch_glyph_alloc(key, atlas);
+            return cached;
+        }
 
         assert!(glyph_info.id.0 != 0, "Can't allocate glyph for id 0");
 
-        let glyph = glyph_info
-            .id
-            .with_scale_and_position(scale, ab_glyph::Point { x: 0.0, y: 0.0 });
+        let glyph = glyph_info.id.with_scale_and_position(
+            scale,
+            ab_glyph::Point {
+                x: sub_pixel_offset_in_pixels,
+                y: 0.0,
+            },
+        );
 
         // Tweak the scale as the user desired
-        let y_offset_in_points = {
-            let logically_scaled = self.ab_glyph_font.pt_scaled(font_size * pixels_per_point);
-            let scale_in_points = scale / pixels_per_point;
-
-            let y_offset_points =
-                ((scale_in_points * self.tweak.y_offset_factor) + self.tweak.y_offset).round_ui();
-
-            // Center scaled glyphs properly:
-            let height = (logically_scaled.ascent() / pixels_per_point).round_ui()
-                + (logically_scaled.descent() / pixels_per_point).round_ui();
-            let y_offset_points = y_offset_points - (1.0 - self.tweak.scale) * 0.5 * height;
-
-            // Round to closest pixel:
-            (y_offset_points * pixels_per_point).round() / pixels_per_point
-        };
+        let y_offset_in_points = self.y_offset_in_points(scale, font_size, pixels_per_point);
 
         let uv_rect = self.ab_glyph_font.outline_glyph(glyph).map(|glyph| {
             let bb = glyph.px_bounds();
@@ -308,17 +495,24 @@ impl FontImpl {
                 let glyph_pos = {
                     let text_alpha_from_coverage = atlas.text_alpha_from_coverage;
                     let (glyph_pos, image) = atlas.allocate((glyph_width, glyph_height));
+                    // Write every texel in the allocated rect, even uncovered ones (coverage
+                    // `0.0` still maps to a real, fully-transparent color). `atlas.allocate` may
+                    // hand back a freed rectangle whose pixels are leftover from a previous,
+                    // differently-shaped glyph, and it does not clear them itself; skipping
+                    // low/no-coverage texels here would let that old glyph's pixels show through
+                    // ("ghosting") wherever this glyph doesn't fully cover the rect.
                     glyph.draw(|x, y, v| {
-                        if 0.0 < v {
-                            let px = glyph_pos.0 + x as usize;
-                            let py = glyph_pos.1 + y as usize;
-                            image[(px, py)] = text_alpha_from_coverage.color_from_coverage(v);
-                        }
+                        let px = glyph_pos.0 + x as usize;
+                        let py = glyph_pos.1 + y as usize;
+                        image[(px, py)] = text_alpha_from_coverage.color_from_coverage(v);
                     });
                     glyph_pos
                 };
 
-                let offset_in_pixels = vec2(bb.min.x, bb.min.y);
+                // We rasterized the glyph shifted right by `sub_pixel_offset_in_pixels` to get
+                // a sharper sub-pixel-accurate shape, so shift the placement back by the same
+                // amount to keep the quad at the pen's actual position.
+                let offset_in_pixels = vec2(bb.min.x - sub_pixel_offset_in_pixels, bb.min.y);
                 let offset = offset_in_pixels / pixels_per_point + y_offset_in_points * Vec2::Y;
                 UvRect {
                     offset,
@@ -331,7 +525,22 @@ impl FontImpl {
                 }
             }
         });
-        let uv_rect = uv_rect.unwrap_or_default();
+
+        // Some fonts (pixel/bitmap fonts, and color emoji fonts) ship pre-rendered bitmap
+        // strikes instead of (or in addition to) outlines. Fall back to blitting the closest
+        // strike rather than showing nothing.
+        let (uv_rect, colored) = uv_rect.map_or_else(
+            || {
+                self.allocate_bitmap_glyph(
+                    glyph_info,
+                    atlas,
+                    scale,
+                    pixels_per_point,
+                    y_offset_in_points,
+                )
+            },
+            |uv_rect| (uv_rect, false),
+        );
 
         let allocation = GlyphAllocation {
             id: glyph_info.id,
@@ -339,10 +548,346 @@ impl FontImpl {
                 / self.ab_glyph_font.height_unscaled())
                 / pixels_per_point,
             uv_rect,
+            sdf: None,
+            colored,
         };
-        entry.insert(allocation);
+        self.glyph_alloc_cache.insert(key, allocation);
+        self.touch_glyph_alloc(key, atlas);
         allocation
     }
+
+    /// Vertical nudge (in points) applied to a glyph rasterized at `scale` physical pixels,
+    /// combining [`FontTweak::y_offset_factor`]/[`FontTweak::y_offset`] with the centering
+    /// correction for [`FontTweak::scale`]. Shared by every rasterization path (coverage, SDF,
+    /// bitmap strikes) so they all place glyphs from the same face identically.
+    fn y_offset_in_points(&self, scale: f32, font_size: f32, pixels_per_point: f32) -> f32 {
+        let logically_scaled = self.ab_glyph_font.pt_scaled(font_size * pixels_per_point);
+        let scale_in_points = scale / pixels_per_point;
+
+        let y_offset_points =
+            ((scale_in_points * self.tweak.y_offset_factor) + self.tweak.y_offset).round_ui();
+
+        // Center scaled glyphs properly:
+        let height = (logically_scaled.ascent() / pixels_per_point).round_ui()
+            + (logically_scaled.descent() / pixels_per_point).round_ui();
+        let y_offset_points = y_offset_points - (1.0 - self.tweak.scale) * 0.5 * height;
+
+        // Round to closest pixel:
+        (y_offset_points * pixels_per_point).round() / pixels_per_point
+    }
+
+    /// Renders `glyph_info` from an embedded bitmap strike close to the em-size (in physical
+    /// pixels) that the pixel scale `scale` corresponds to,
+    /// for fonts that ship pre-rasterized glyphs (monochrome `EBDT`/`CBLC` pixel fonts, or
+    /// color `CBDT`/`sbix` emoji) instead of, or in addition to, outlines.
+    ///
+    /// Returns ([`UvRect::default`], `false`) if the face has no outline *and* no usable
+    /// strike for this glyph (including formats we don't decode, e.g. PNG strikes — epaint
+    /// core has no image decoder dependency), in which case the caller falls back to the
+    /// replacement character. Nothing is allocated from `atlas` unless we can actually fill it.
+    ///
+    /// The returned `bool` is `true` if the strike is a full-color glyph (see
+    /// [`GlyphAllocation::colored`]) rather than a grayscale coverage mask.
+    fn allocate_bitmap_glyph(
+        &self,
+        glyph_info: GlyphInfo,
+        atlas: &mut TextureAtlas,
+        scale: f32,
+        pixels_per_point: f32,
+        y_offset_in_points: f32,
+    ) -> (UvRect, bool) {
+        // `scale` is an `ab_glyph` pixel scale (line height); strikes are selected and stored
+        // by pixels per em.
+        let pixels_per_em = scale / self.ab_glyph_font.pt_scale_factor(1.0);
+        let Some(image) = self
+            .ab_glyph_font
+            .glyph_raster_image2(glyph_info.id, pixels_per_em.round() as u16)
+        else {
+            return (UvRect::default(), false);
+        };
+
+        let glyph_width = image.width as usize;
+        let glyph_height = image.height as usize;
+        if glyph_width == 0 || glyph_height == 0 {
+            return (UvRect::default(), false);
+        }
+
+        let Some(bits_per_pixel) = bitmap_strike_bits_per_pixel(image.format) else {
+            // Unsupported format — most notably `Png`: decoding it would require pulling an
+            // `image`-crate-sized dependency into epaint core, which isn't free to add here.
+            return (UvRect::default(), false);
+        };
+        let row_is_byte_aligned = bitmap_strike_row_is_byte_aligned(image.format);
+        // Only `BitmapPremulBgra32` is a full-color format; the rest are grayscale coverage.
+        let colored = bits_per_pixel == 32;
+        let expected_bytes = if colored {
+            glyph_width * glyph_height * 4
+        } else if row_is_byte_aligned {
+            (glyph_width * bits_per_pixel).div_ceil(8) * glyph_height
+        } else {
+            (glyph_width * glyph_height * bits_per_pixel).div_ceil(8)
+        };
+        if image.data.len() < expected_bytes {
+            return (UvRect::default(), false);
+        }
+
+        // Only allocate atlas space once we know we can actually decode this strike.
+        let (glyph_pos, atlas_image) = atlas.allocate((glyph_width, glyph_height));
+
+        if bits_per_pixel == 32 {
+            // `BitmapPremulBgra32`: already-premultiplied color, B-G-R-A byte order.
+            for y in 0..glyph_height {
+                for x in 0..glyph_width {
+                    let i = (y * glyph_width + x) * 4;
+                    let [b, g, r, a] =
+                        [image.data[i], image.data[i + 1], image.data[i + 2], image.data[i + 3]];
+                    atlas_image[(glyph_pos.0 + x, glyph_pos.1 + y)] =
+                        crate::Color32::from_rgba_premultiplied(r, g, b, a);
+                }
+            }
+        } else {
+            // Monochrome/grayscale coverage strike: unpack `bits_per_pixel`-wide samples,
+            // MSB first, and reuse the same coverage-to-alpha mapping as outline rasterization.
+            let row_bytes = (glyph_width * bits_per_pixel).div_ceil(8);
+            for y in 0..glyph_height {
+                for x in 0..glyph_width {
+                    let coverage = unpack_bitmap_strike_coverage(
+                        &image.data,
+                        bits_per_pixel,
+                        row_is_byte_aligned,
+                        row_bytes,
+                        glyph_width,
+                        x,
+                        y,
+                    );
+                    atlas_image[(glyph_pos.0 + x, glyph_pos.1 + y)] =
+                        atlas.text_alpha_from_coverage.color_from_coverage(coverage);
+                }
+            }
+        }
+
+        // Strikes are stored at their own `pixels_per_em`, which may not exactly match the
+        // scale we asked for; rescale the bearing and size to the scale we're drawing at.
+        let strike_scale = pixels_per_em / image.pixels_per_em as f32;
+        let offset_in_pixels = vec2(image.origin.x, -image.origin.y) * strike_scale;
+        let offset = offset_in_pixels / pixels_per_point + y_offset_in_points * Vec2::Y;
+        let uv_rect = UvRect {
+            offset,
+            size: vec2(glyph_width as f32, glyph_height as f32) * strike_scale / pixels_per_point,
+            min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
+            max: [
+                (glyph_pos.0 + glyph_width) as u16,
+                (glyph_pos.1 + glyph_height) as u16,
+            ],
+        };
+        (uv_rect, colored)
+    }
+
+    /// Marks `key`'s glyph allocation as used this frame, and evicts the least-recently-used
+    /// allocation not used *this* frame (freeing its atlas rectangle for reuse) if the cache
+    /// is now over [`FontTweak::glyph_cache_capacity`].
+    ///
+    /// Keeps memory bounded for long-running apps that cycle through many distinct sizes or a
+    /// large glyph corpus, instead of growing the atlas forever. Never evicts an entry touched
+    /// during the current frame: once per-frame glyph usage exceeds capacity, doing so would
+    /// free an atlas rectangle this frame's mesh has already queued a quad against.
+    fn touch_glyph_alloc(
+        &mut self,
+        key: (GlyphInfo, OrderedFloat<f32>, u8),
+        atlas: &mut TextureAtlas,
+    ) {
+        let frame = atlas.frame_index();
+        self.glyph_alloc_lru.touch(key, frame);
+
+        let capacity = self.tweak.glyph_cache_capacity.max(1);
+        while self.glyph_alloc_lru.len() > capacity {
+            let Some(lru_key) = self.glyph_alloc_lru.pop_lru(frame) else {
+                break;
+            };
+            if let Some(evicted) = self.glyph_alloc_cache.remove(&lru_key) {
+                atlas.free(evicted.uv_rect);
+            }
+        }
+    }
+
+    /// Same as [`Self::touch_glyph_alloc`], but for [`Self::sdf_glyph_raster_cache`].
+    fn touch_sdf_glyph_alloc(&mut self, glyph_info: GlyphInfo, atlas: &mut TextureAtlas) {
+        let frame = atlas.frame_index();
+        self.sdf_glyph_alloc_lru.touch(glyph_info, frame);
+
+        let capacity = self.tweak.glyph_cache_capacity.max(1);
+        while self.sdf_glyph_alloc_lru.len() > capacity {
+            let Some(lru_key) = self.sdf_glyph_alloc_lru.pop_lru(frame) else {
+                break;
+            };
+            if let Some(Some(evicted)) = self.sdf_glyph_raster_cache.remove(&lru_key) {
+                atlas.free(UvRect {
+                    offset: Vec2::ZERO,
+                    size: Vec2::ZERO,
+                    min: evicted.min,
+                    max: evicted.max,
+                });
+            }
+        }
+    }
+
+    /// Rasterizes `glyph_info` as a signed-distance field instead of a coverage mask, then
+    /// rescales it to the requested `font_size`/`pixels_per_point`/`cap_height_scale`.
+    ///
+    /// Unlike [`Self::allocate_glyph`]'s coverage path, the raster itself ([`Self::rasterize_sdf_glyph`])
+    /// is cached as a single entry per glyph id regardless of the size it's later drawn at, so
+    /// zooming or animating text size doesn't multiply atlas entries, and the renderer gets
+    /// sharp edges at any scale by thresholding the sampled distance around `0.5`. The
+    /// returned [`GlyphAllocation`] (advance width, draw-space `uv_rect`), however, must still
+    /// be computed fresh every call: it depends on the actual draw size, not just the glyph id.
+    fn allocate_sdf_glyph(
+        &mut self,
+        glyph_info: GlyphInfo,
+        atlas: &mut TextureAtlas,
+        font_size: f32,
+        pixels_per_point: f32,
+        cap_height_scale: f32,
+    ) -> GlyphAllocation {
+        let raster = if let Some(&cached) = self.sdf_glyph_raster_cache.get(&glyph_info) {
+            self.touch_sdf_glyph_alloc(glyph_info, atlas);
+            cached
+        } else {
+            let raster = self.rasterize_sdf_glyph(glyph_info, atlas);
+            self.sdf_glyph_raster_cache.insert(glyph_info, raster);
+            self.touch_sdf_glyph_alloc(glyph_info, atlas);
+            raster
+        };
+
+        let draw_scale = self
+            .ab_glyph_font
+            .pt_scale_factor(font_size * self.tweak.scale * cap_height_scale * pixels_per_point);
+        let advance_width = (glyph_info.advance_width_unscaled.0 * draw_scale
+            / self.ab_glyph_font.height_unscaled())
+            / pixels_per_point;
+
+        let Some(raster) = raster else {
+            return GlyphAllocation {
+                id: glyph_info.id,
+                advance_width,
+                uv_rect: UvRect::default(),
+                sdf: None,
+                colored: false,
+            };
+        };
+
+        // The raster was rasterized once at `SDF_REF_EM_PX`; rescale its size-independent
+        // geometry to the scale we're actually drawing at.
+        let ref_scale = self.ab_glyph_font.pt_scale_factor(SDF_REF_EM_PX);
+        let size_ratio = draw_scale / ref_scale;
+        let bb_size_px = vec2(
+            (raster.max[0] - raster.min[0]) as f32,
+            (raster.max[1] - raster.min[1]) as f32,
+        );
+
+        // Same per-face vertical nudge the coverage path applies, e.g. for fallback faces
+        // with a `FontTweak::y_offset`/`y_offset_factor` set for baseline alignment.
+        let y_offset_in_points = self.y_offset_in_points(draw_scale, font_size, pixels_per_point);
+
+        GlyphAllocation {
+            id: glyph_info.id,
+            advance_width,
+            uv_rect: UvRect {
+                offset: raster.offset_at_ref_em_px * size_ratio / pixels_per_point
+                    + y_offset_in_points * Vec2::Y,
+                size: bb_size_px * size_ratio / pixels_per_point,
+                min: raster.min,
+                max: raster.max,
+            },
+            sdf: Some(GlyphSdfInfo {
+                spread_px: SDF_SPREAD_PX,
+                ref_em_px: SDF_REF_EM_PX,
+            }),
+            colored: false,
+        }
+    }
+
+    /// Rasterizes `glyph_info`'s signed distance field at [`SDF_REF_EM_PX`] and stores it in
+    /// the atlas, returning its size-independent geometry (or `None` if the glyph has no
+    /// outline). Pure size-independent raster data only — see [`Self::allocate_sdf_glyph`]
+    /// for the per-call rescaling to the actual draw size.
+    fn rasterize_sdf_glyph(
+        &mut self,
+        glyph_info: GlyphInfo,
+        atlas: &mut TextureAtlas,
+    ) -> Option<SdfGlyphRaster> {
+        assert!(glyph_info.id.0 != 0, "Can't allocate glyph for id 0");
+
+        let scale = self.ab_glyph_font.pt_scale_factor(SDF_REF_EM_PX);
+        let glyph = glyph_info
+            .id
+            .with_scale_and_position(scale, ab_glyph::Point { x: 0.0, y: 0.0 });
+        let outlined = self.ab_glyph_font.outline_glyph(glyph)?;
+
+        let bb = outlined.px_bounds();
+        let spread = SDF_SPREAD_PX.ceil() as i32;
+        let glyph_width = bb.width() as i32;
+        let glyph_height = bb.height() as i32;
+        if glyph_width <= 0 || glyph_height <= 0 {
+            return None;
+        }
+
+        // Rasterize coverage into a buffer padded by the spread, so the distance field has
+        // room to extend past the glyph's own edges on every side.
+        let padded_w = (glyph_width + 2 * spread) as usize;
+        let padded_h = (glyph_height + 2 * spread) as usize;
+        let mut coverage = vec![0.0_f32; padded_w * padded_h];
+        outlined.draw(|x, y, v| {
+            let px = x as i32 + spread;
+            let py = y as i32 + spread;
+            coverage[py as usize * padded_w + px as usize] = v;
+        });
+        let is_inside = |coverage: &[f32], x: i32, y: i32| -> bool {
+            if x < 0 || y < 0 || x >= padded_w as i32 || y >= padded_h as i32 {
+                false
+            } else {
+                coverage[y as usize * padded_w + x as usize] >= 0.5
+            }
+        };
+
+        let (glyph_pos, image) = atlas.allocate((padded_w, padded_h));
+        for y in 0..padded_h as i32 {
+            for x in 0..padded_w as i32 {
+                let inside = is_inside(&coverage, x, y);
+
+                // Nearest pixel of the opposite sign within the spread radius.
+                let mut nearest_opposite = SDF_SPREAD_PX;
+                for dy in -spread..=spread {
+                    for dx in -spread..=spread {
+                        if dx == 0 && dy == 0 {
+                            continue;
+                        }
+                        if is_inside(&coverage, x + dx, y + dy) != inside {
+                            let d = ((dx * dx + dy * dy) as f32).sqrt();
+                            nearest_opposite = nearest_opposite.min(d);
+                        }
+                    }
+                }
+
+                let signed_px = if inside {
+                    nearest_opposite
+                } else {
+                    -nearest_opposite
+                };
+                let px = glyph_pos.0 + x as usize;
+                let py = glyph_pos.1 + y as usize;
+                image[(px, py)] = crate::Color32::from_gray(sdf_signed_distance_to_gray(signed_px, SDF_SPREAD_PX));
+            }
+        }
+
+        Some(SdfGlyphRaster {
+            min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
+            max: [
+                (glyph_pos.0 + padded_w) as u16,
+                (glyph_pos.1 + padded_h) as u16,
+            ],
+            offset_at_ref_em_px: vec2(bb.min.x - spread as f32, bb.min.y - spread as f32),
+        })
+    }
 }
 
 // TODO(emilk): rename?
@@ -402,14 +947,18 @@ impl Font<'_> {
     }
 
     /// Width of this character in points.
-    pub fn glyph_width(&mut self, c: char, font_size: f32) -> f32 {
+    pub fn glyph_width(&mut self, c: char, font_size: f32, pixels_per_point: f32) -> f32 {
         let (key, glyph_info) = self.glyph_info(c);
+        // Apply the same cap-height correction `allocate_glyph` uses for this face (see
+        // `FontTweak::match_cap_height`). `FontTweak::scale` and the rounding to whole
+        // physical pixels that `allocate_glyph` applies are not included here.
+        let cap_height_scale = self.cap_height_scale(key, font_size, pixels_per_point);
         let font = &self
             .fonts_by_id
             .get(&key)
             .expect("Nonexistent font ID")
             .ab_glyph_font;
-        glyph_info.advance_width_unscaled.0 * font.pt_scale_factor(font_size)
+        glyph_info.advance_width_unscaled.0 * font.pt_scale_factor(font_size * cap_height_scale)
             / font.height_unscaled()
     }
 
@@ -454,22 +1003,88 @@ impl Font<'_> {
     }
 
     #[inline]
+    /// `pen_x_in_points` is the fractional horizontal pen position this glyph will be drawn
+    /// at; it's used for sub-pixel positioning (see [`FontTweak::subpixel_positions`]) and has
+    /// no effect unless that's enabled.
     pub(crate) fn font_impl_and_glyph_alloc(
         &mut self,
         c: char,
         font_size: f32,
         pixels_per_point: f32,
+        pen_x_in_points: f32,
     ) -> (Option<&FontImpl>, GlyphAllocation) {
         if self.cached_family.fonts.is_empty() {
             return (None, Default::default());
         }
         let (key, glyph_info) = self.glyph_info(c);
+        let cap_height_scale = self.cap_height_scale(key, font_size, pixels_per_point);
         let font_impl = self.fonts_by_id.get_mut(&key).expect("Nonexistent font ID");
-        let allocated_glyph =
-            font_impl.allocate_glyph(glyph_info, self.atlas, font_size, pixels_per_point);
+        let allocated_glyph = font_impl.allocate_glyph(
+            glyph_info,
+            self.atlas,
+            font_size,
+            pixels_per_point,
+            cap_height_scale,
+            pen_x_in_points,
+        );
         (Some(font_impl), allocated_glyph)
     }
 
+    /// Correction factor applied to `key`'s rasterization scale so that, when
+    /// [`FontTweak::match_cap_height`] is enabled on it, its cap-height matches the cap-height
+    /// of the family's primary face in physical pixels.
+    ///
+    /// Always `1.0` for the primary face itself, and whenever either face's cap-height can't
+    /// be measured (e.g. neither has an 'H', 'I', or 'X' glyph).
+    fn cap_height_scale(&mut self, key: FontFaceKey, font_size: f32, pixels_per_point: f32) -> f32 {
+        let Some(primary_key) = self.cached_family.fonts.first().copied() else {
+            return 1.0;
+        };
+        if key == primary_key {
+            return 1.0;
+        }
+
+        let Some(font_impl) = self.fonts_by_id.get(&key) else {
+            return 1.0;
+        };
+        if !font_impl.tweak.match_cap_height {
+            return 1.0;
+        }
+        let fallback_scale = font_impl
+            .ab_glyph_font
+            .pt_scale_factor(font_size * font_impl.tweak.scale * pixels_per_point)
+            .round();
+
+        let Some(primary_impl) = self.fonts_by_id.get(&primary_key) else {
+            return 1.0;
+        };
+        let primary_scale = primary_impl
+            .ab_glyph_font
+            .pt_scale_factor(font_size * primary_impl.tweak.scale * pixels_per_point)
+            .round();
+
+        let Some(primary_cap_px) = self
+            .fonts_by_id
+            .get_mut(&primary_key)
+            .and_then(|f| f.cap_height_px(primary_scale))
+        else {
+            return 1.0;
+        };
+        let Some(fallback_cap_px) = self
+            .fonts_by_id
+            .get_mut(&key)
+            .and_then(|f| f.cap_height_px(fallback_scale))
+        else {
+            return 1.0;
+        };
+
+        if fallback_cap_px <= 0.0 {
+            return 1.0;
+        }
+
+        primary_cap_px / fallback_cap_px
+    }
+
     pub(crate) fn ascent(&self, font_size: f32) -> f32 {
         if let Some(first) = self.cached_family.fonts.first() {
             let first = self.fonts_by_id.get(first).expect("Nonexistent font ID");
@@ -526,3 +1141,178 @@ fn invisible_char(c: char) -> bool {
             | '\u{FEFF}' // ZERO WIDTH NO-BREAK SPACE
     )
 }
+
+/// Bits per sample for the embedded bitmap strike formats we know how to decode, or `None`
+/// for formats epaint core doesn't decode (currently `Png`, which would need an image-decoding
+/// dependency we don't want in this crate).
+#[inline]
+fn bitmap_strike_bits_per_pixel(format: ab_glyph::GlyphImageFormat) -> Option<usize> {
+    use ab_glyph::GlyphImageFormat::*;
+    match format {
+        BitmapMono | BitmapMonoPacked => Some(1),
+        BitmapGray2 | BitmapGray2Packed => Some(2),
+        BitmapGray4 | BitmapGray4Packed => Some(4),
+        BitmapGray8 => Some(8),
+        BitmapPremulBgra32 => Some(32),
+        Png => None,
+        _ => None,
+    }
+}
+
+/// Whether each row of `format` starts on a byte boundary (padded to a whole number of bytes
+/// per row) or samples are packed contiguously with no per-row padding.
+///
+/// The non-`Packed` variants (`BitmapMono`, `BitmapGray2`, `BitmapGray4`) pad each row; their
+/// `*Packed` counterparts don't, so a `Mono` strike and a `MonoPacked` strike of the same
+/// dimensions can have different byte lengths and must not share decode logic.
+#[inline]
+fn bitmap_strike_row_is_byte_aligned(format: ab_glyph::GlyphImageFormat) -> bool {
+    use ab_glyph::GlyphImageFormat::*;
+    !matches!(format, BitmapMonoPacked | BitmapGray2Packed | BitmapGray4Packed)
+}
+
+/// Reads the `bits_per_pixel`-wide, MSB-first coverage sample at `(x, y)` out of a grayscale
+/// bitmap strike's raw bytes and normalizes it to `0.0..=1.0`.
+///
+/// `row_bytes` is only used when `row_is_byte_aligned`; packed strikes have no per-row padding.
+#[inline]
+fn unpack_bitmap_strike_coverage(
+    data: &[u8],
+    bits_per_pixel: usize,
+    row_is_byte_aligned: bool,
+    row_bytes: usize,
+    glyph_width: usize,
+    x: usize,
+    y: usize,
+) -> f32 {
+    let max_level = (1u32 << bits_per_pixel) - 1;
+    let bit_index = if row_is_byte_aligned {
+        y * row_bytes * 8 + x * bits_per_pixel
+    } else {
+        (y * glyph_width + x) * bits_per_pixel
+    };
+    let byte = data[bit_index / 8];
+    let shift = 8 - bits_per_pixel - (bit_index % 8);
+    let level = (byte >> shift) & (max_level as u8);
+    level as f32 / max_level as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitmap_strike_bits_per_pixel_known_formats() {
+        use ab_glyph::GlyphImageFormat::*;
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapMono), Some(1));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapMonoPacked), Some(1));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapGray2), Some(2));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapGray2Packed), Some(2));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapGray4), Some(4));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapGray4Packed), Some(4));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapGray8), Some(8));
+        assert_eq!(bitmap_strike_bits_per_pixel(BitmapPremulBgra32), Some(32));
+        assert_eq!(bitmap_strike_bits_per_pixel(Png), None);
+    }
+
+    #[test]
+    fn bitmap_strike_row_alignment_matches_packed_variants() {
+        use ab_glyph::GlyphImageFormat::*;
+        assert!(bitmap_strike_row_is_byte_aligned(BitmapMono));
+        assert!(!bitmap_strike_row_is_byte_aligned(BitmapMonoPacked));
+        assert!(bitmap_strike_row_is_byte_aligned(BitmapGray2));
+        assert!(!bitmap_strike_row_is_byte_aligned(BitmapGray2Packed));
+        assert!(bitmap_strike_row_is_byte_aligned(BitmapGray4));
+        assert!(!bitmap_strike_row_is_byte_aligned(BitmapGray4Packed));
+        assert!(bitmap_strike_row_is_byte_aligned(BitmapGray8));
+    }
+
+    #[test]
+    fn unpack_bitmap_strike_coverage_mono_byte_aligned() {
+        // 1 bit per pixel, byte-aligned rows: width 3 pads each row up to 1 byte.
+        // Row 0: 0b101_00000 -> pixels [1, 0, 1]. Row 1: 0b010_00000 -> pixels [0, 1, 0].
+        let data = [0b1010_0000, 0b0100_0000];
+        let row_bytes = 1;
+        for (x, expected) in [(0, 1.0), (1, 0.0), (2, 1.0)] {
+            let coverage = unpack_bitmap_strike_coverage(&data, 1, true, row_bytes, 3, x, 0);
+            assert_eq!(coverage, expected, "row 0, x={x}");
+        }
+        for (x, expected) in [(0, 0.0), (1, 1.0), (2, 0.0)] {
+            let coverage = unpack_bitmap_strike_coverage(&data, 1, true, row_bytes, 3, x, 1);
+            assert_eq!(coverage, expected, "row 1, x={x}");
+        }
+    }
+
+    #[test]
+    fn unpack_bitmap_strike_coverage_packed_crosses_byte_boundary() {
+        // 2 bits per pixel, packed (no row padding): width 3 -> 6 bits per row, so row 0 fills
+        // the top 6 bits of the first byte and row 1 starts in its bottom 2 bits, spilling into
+        // the second byte. Row 0 samples: 0b01, 0b10, 0b11; row 1 samples: 0b10, 0b01, 0b00;
+        // packed MSB-first as 0b01_10_11_10, 0b01_00_0000.
+        let data = [0b0110_1110, 0b0100_0000];
+        let max_level = 3.0;
+        assert_eq!(
+            unpack_bitmap_strike_coverage(&data, 2, false, 0, 3, 0, 0),
+            1.0 / max_level
+        );
+        assert_eq!(
+            unpack_bitmap_strike_coverage(&data, 2, false, 0, 3, 1, 0),
+            2.0 / max_level
+        );
+        assert_eq!(
+            unpack_bitmap_strike_coverage(&data, 2, false, 0, 3, 2, 0),
+            3.0 / max_level
+        );
+        assert_eq!(
+            unpack_bitmap_strike_coverage(&data, 2, false, 0, 3, 0, 1),
+            2.0 / max_level
+        );
+        assert_eq!(
+            unpack_bitmap_strike_coverage(&data, 2, false, 0, 3, 1, 1),
+            1.0 / max_level
+        );
+        assert_eq!(
+            unpack_bitmap_strike_coverage(&data, 2, false, 0, 3, 2, 1),
+            0.0
+        );
+    }
+
+    #[test]
+    fn lru_tracker_pop_lru_skips_current_frame_entries() {
+        let mut lru = LruTracker::<u32>::default();
+        lru.touch(1, 0);
+        lru.touch(2, 0);
+        lru.touch(3, 1); // touched on the current frame (1) we'll evict from below.
+
+        // 1 and 2 are stale (frame 0); 3 was just touched on frame 1 and must survive.
+        assert_eq!(lru.pop_lru(1), Some(1));
+        assert_eq!(lru.pop_lru(1), Some(2));
+        assert_eq!(lru.pop_lru(1), None);
+        assert_eq!(lru.len(), 1);
+    }
+
+    #[test]
+    fn sdf_signed_distance_to_gray_clamps_and_centers_on_the_edge() {
+        // Exactly on the glyph edge maps to the midpoint gray level.
+        assert_eq!(sdf_signed_distance_to_gray(0.0, 4.0), 128);
+        // Fully inside (at or beyond the spread radius) saturates to white...
+        assert_eq!(sdf_signed_distance_to_gray(4.0, 4.0), 255);
+        assert_eq!(sdf_signed_distance_to_gray(100.0, 4.0), 255);
+        // ...and fully outside saturates to black.
+        assert_eq!(sdf_signed_distance_to_gray(-4.0, 4.0), 0);
+        assert_eq!(sdf_signed_distance_to_gray(-100.0, 4.0), 0);
+        // Halfway to the spread radius lands halfway between edge and saturation.
+        assert_eq!(sdf_signed_distance_to_gray(2.0, 4.0), 191);
+    }
+
+    #[test]
+    fn lru_tracker_touch_moves_entry_to_most_recently_used() {
+        let mut lru = LruTracker::<u32>::default();
+        lru.touch(1, 0);
+        lru.touch(2, 0);
+        lru.touch(1, 0); // re-touching 1 should make 2 the stale one now.
+
+        assert_eq!(lru.pop_lru(1), Some(2));
+        assert_eq!(lru.len(), 1);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Color32, ColorImage, text::font::UvRect};
+
+/// How to turn rasterized glyph coverage (0 = transparent, 1 = fully covered)
+/// into the alpha channel baked into the atlas.
+///
+/// Text looks thin if alpha is directly proportional to coverage, because the eye
+/// perceives small bright shapes on a dark background as thinner than they are
+/// (and vice versa). [`Self::Gamma`] compensates for this.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextureAtlasTextAlphaFromCoverage {
+    /// Alpha equals coverage.
+    Linear,
+
+    /// Alpha is coverage raised to the given power (`< 1.0` boosts faint coverage).
+    Gamma(f32),
+}
+
+impl Default for TextureAtlasTextAlphaFromCoverage {
+    fn default() -> Self {
+        Self::Gamma(0.55)
+    }
+}
+
+impl TextureAtlasTextAlphaFromCoverage {
+    /// Map a coverage value in `0..=1` to a fully white [`Color32`] with the resulting alpha.
+    pub fn color_from_coverage(self, coverage: f32) -> Color32 {
+        let coverage = coverage.clamp(0.0, 1.0);
+        let alpha = match self {
+            Self::Linear => coverage,
+            Self::Gamma(gamma) => coverage.powf(gamma),
+        };
+        Color32::from_white_alpha((alpha * 255.0).round() as u8)
+    }
+}
+
+/// A texture for storing rasterized glyphs (and other small images) that the renderer
+/// uploads to the GPU once and samples many times.
+///
+/// Space is handed out with [`Self::allocate`] and given back with [`Self::free`]. Freed
+/// rectangles of a given size are kept on a per-size free list so that, e.g., an evicted
+/// glyph slot can be reused by the next glyph of the same size without growing the atlas
+/// or fragmenting it — this matters because glyph caches in [`super::text::font`] evict
+/// entries every frame once they're over capacity.
+pub struct TextureAtlas {
+    image: ColorImage,
+
+    /// How rasterized glyph coverage is mapped to alpha when caches bake new glyphs.
+    pub text_alpha_from_coverage: TextureAtlasTextAlphaFromCoverage,
+
+    /// Where the next never-before-used rectangle will be placed, if no freed rectangle fits.
+    cursor: (usize, usize),
+    cur_row_height: usize,
+
+    /// Freed rectangles, bucketed by exact `(width, height)` so they can be handed back out
+    /// without a linear search. LIFO per bucket (a `Vec` used as a stack) for cache locality.
+    free_rects: ahash::HashMap<(usize, usize), Vec<(usize, usize)>>,
+
+    /// Bumped once per frame by the caller (e.g. the painter) so glyph caches can tell
+    /// "used this frame" apart from "stale".
+    frame_index: u64,
+}
+
+impl TextureAtlas {
+    pub fn new(size: [usize; 2]) -> Self {
+        assert!(size[0] >= 1 && size[1] >= 1);
+        Self {
+            image: ColorImage::new(size, Color32::TRANSPARENT),
+            text_alpha_from_coverage: TextureAtlasTextAlphaFromCoverage::default(),
+            cursor: (0, 0),
+            cur_row_height: 0,
+            free_rects: Default::default(),
+            frame_index: 0,
+        }
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.image.width(), self.image.height())
+    }
+
+    /// The current frame counter, bumped by [`Self::advance_frame`].
+    ///
+    /// Glyph caches stamp this on every allocation they touch so eviction can skip
+    /// entries that were (re-)used during the frame currently being built.
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+
+    /// Call once per frame, before any glyphs are allocated for that frame.
+    pub fn advance_frame(&mut self) {
+        self.frame_index += 1;
+    }
+
+    /// Reserve a `width x height` rectangle and return its top-left position together with
+    /// a mutable view onto the backing image to paint into.
+    ///
+    /// Prefers reusing a freed rectangle of the exact same size over growing the atlas.
+    pub fn allocate(&mut self, size: (usize, usize)) -> ((usize, usize), &mut ColorImage) {
+        if let Some(freed) = self.free_rects.get_mut(&size).and_then(Vec::pop) {
+            return (freed, &mut self.image);
+        }
+
+        let (width, height) = size;
+        if self.cursor.0 + width > self.image.width() {
+            self.cursor = (0, self.cursor.1 + self.cur_row_height);
+            self.cur_row_height = 0;
+        }
+        self.grow_to_fit(self.cursor.0 + width, self.cursor.1 + height);
+
+        let pos = self.cursor;
+        self.cursor.0 += width;
+        self.cur_row_height = self.cur_row_height.max(height);
+        (pos, &mut self.image)
+    }
+
+    /// Return a previously allocated rectangle to the free list so it can be reused by a
+    /// later allocation of the same size. Does not clear the pixels it covers; whoever
+    /// reuses the slot is expected to overwrite it fully.
+    pub fn free(&mut self, rect: UvRect) {
+        if rect.is_nothing() {
+            return;
+        }
+        let width = (rect.max[0] - rect.min[0]) as usize;
+        let height = (rect.max[1] - rect.min[1]) as usize;
+        if width == 0 || height == 0 {
+            return;
+        }
+        self.free_rects
+            .entry((width, height))
+            .or_default()
+            .push((rect.min[0] as usize, rect.min[1] as usize));
+    }
+
+    /// Grow the backing image so that `(min_width, min_height)` fits, preserving existing content.
+    fn grow_to_fit(&mut self, min_width: usize, min_height: usize) {
+        if min_width <= self.image.width() && min_height <= self.image.height() {
+            return;
+        }
+        let new_width = self.image.width().max(min_width);
+        let new_height = self.image.height().max(min_height);
+        let mut new_image = ColorImage::new([new_width, new_height], Color32::TRANSPARENT);
+        for y in 0..self.image.height() {
+            for x in 0..self.image.width() {
+                new_image[(x, y)] = self.image[(x, y)];
+            }
+        }
+        self.image = new_image;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uv_rect_at(pos: (usize, usize), size: (usize, usize)) -> UvRect {
+        UvRect {
+            offset: Default::default(),
+            size: Default::default(),
+            min: [pos.0 as u16, pos.1 as u16],
+            max: [(pos.0 + size.0) as u16, (pos.1 + size.1) as u16],
+        }
+    }
+
+    #[test]
+    fn allocate_bumps_cursor_when_nothing_is_free() {
+        let mut atlas = TextureAtlas::new([4, 4]);
+        let (pos_a, _) = atlas.allocate((2, 2));
+        assert_eq!(pos_a, (0, 0));
+        let (pos_b, _) = atlas.allocate((2, 2));
+        assert_eq!(pos_b, (2, 0));
+    }
+
+    #[test]
+    fn free_then_allocate_same_size_reuses_the_rect() {
+        let mut atlas = TextureAtlas::new([4, 4]);
+        let (pos, _) = atlas.allocate((2, 2));
+        atlas.free(uv_rect_at(pos, (2, 2)));
+        let (reused_pos, _) = atlas.allocate((2, 2));
+        assert_eq!(reused_pos, pos);
+    }
+
+    #[test]
+    fn free_then_allocate_different_size_does_not_reuse_the_rect() {
+        let mut atlas = TextureAtlas::new([8, 8]);
+        let (pos, _) = atlas.allocate((2, 2));
+        atlas.free(uv_rect_at(pos, (2, 2)));
+        let (new_pos, _) = atlas.allocate((3, 3));
+        assert_ne!(new_pos, pos);
+    }
+
+    #[test]
+    fn free_does_not_clear_pixels_whoever_reuses_the_slot_must_overwrite_fully() {
+        // Documents the contract stated on `free`'s doc comment: the caller that reuses a
+        // freed rect is responsible for writing every texel itself, since a coverage path
+        // that only writes "covered" texels would otherwise show the previous glyph through
+        // the gaps ("ghosting").
+        let mut atlas = TextureAtlas::new([4, 4]);
+        let (pos, image) = atlas.allocate((2, 2));
+        image[(pos.0, pos.1)] = Color32::WHITE;
+        atlas.free(uv_rect_at(pos, (2, 2)));
+
+        let (reused_pos, image) = atlas.allocate((2, 2));
+        assert_eq!(reused_pos, pos);
+        assert_eq!(image[(pos.0, pos.1)], Color32::WHITE);
+    }
+}